@@ -13,27 +13,32 @@ fn main() -> litemdb::Result<()> {
     // The maximum size of database in bytes (a high value is recommended).
     let map_size = 4096 * 4096 * 64;
 
+    // The maximum number of named databases this environment may open.
+    let max_dbs = 1;
+
     // Read & write permissions, see https://en.wikipedia.org/wiki/File-system_permissions
     let mode = 0o666;
 
-    // Create and open the LMDB environment. Under the hood it will also create a
-    // shared database handle so that we don't have to worry about it.
-    let env = litemdb::Environment::open(path, flags, map_size, mode)?;
+    // Create and open the LMDB environment.
+    let env = litemdb::Environment::open(path, flags, map_size, max_dbs, mode)?;
 
     // We use [`Arc`] because [`Environment`] is supposed to be shared between
     // threads.
     let env = Arc::new(env);
 
+    // Open (creating, if necessary) the database we'll use.
+    let db = env.open_database("hello_world", true, litemdb::Comparator::Bytewise)?;
+
     let (key, data) = (b"hello_world", b"Hello, World!");
 
     // Begin a write transaction.
     let mut txn = env.begin_transaction(litemdb::transaction::Flags::empty())?;
 
     // Insert some data.
-    txn.put(key, data)?;
+    txn.put(&db, key, data, litemdb::transaction::WriteFlags::empty())?;
 
     // Get that same data back.
-    let view = txn.get(key)?.unwrap();
+    let view = txn.get(&db, key)?.unwrap();
 
     // Verify it's the same.
     assert_eq!(view.as_ref(), data);
@@ -42,7 +47,7 @@ fn main() -> litemdb::Result<()> {
     println!("{}", std::str::from_utf8(data.as_ref()).unwrap());
 
     // Delete it.
-    txn.del(key)?;
+    txn.del(&db, key)?;
 
     // Commit the transaction.
     txn.commit()?;
@@ -9,7 +9,7 @@
 
 use std::{
     ffi::CStr,
-    fmt::{Debug, Formatter},
+    fmt::{self, Display, Formatter},
 };
 
 mod sys {
@@ -26,32 +26,124 @@ mod sys {
     include!(concat!(env!("OUT_DIR"), "/lmdb.rs"));
 }
 
+pub use comparator::Comparator;
+pub use cursor::Cursor;
+pub use database::Database;
 pub use environment::Environment;
+pub use info::EnvInfo;
+pub use stat::Stat;
 pub use transaction::Transaction;
 
-#[derive(Copy, Clone)]
-pub struct Error(i32);
+/// An error returned by the LMDB API, distinguishing the common recoverable
+/// conditions from an [`Other`][Error::Other] catch-all for the rest.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `MDB_KEYEXIST`: key/data pair already exists.
+    KeyExist,
+    /// `MDB_NOTFOUND`: key/data pair not found (EOF).
+    NotFound,
+    /// `MDB_PAGE_NOTFOUND`: requested page not found.
+    PageNotFound,
+    /// `MDB_CORRUPTED`: located page was wrong type.
+    Corrupted,
+    /// `MDB_MAP_FULL`: environment map size limit reached.
+    MapFull,
+    /// `MDB_MAP_RESIZED`: database contents grew beyond environment map size.
+    MapResized,
+    /// `MDB_TXN_FULL`: transaction has too many dirty pages.
+    TxnFull,
+    /// `MDB_READERS_FULL`: environment maxreaders limit reached.
+    ReadersFull,
+    /// `MDB_DBS_FULL`: environment maxdbs limit reached.
+    DbsFull,
+    /// `MDB_INVALID`: file is not an LMDB file.
+    Invalid,
+    /// Any other LMDB or system errno value not covered above.
+    Other(i32),
+    /// A key was the wrong length for the [`Database`][crate::Database]'s
+    /// [`Comparator`][crate::Comparator], which would otherwise cause the
+    /// comparator to read past the end of the key.
+    InvalidKeyLength {
+        /// The key length required by the database's comparator.
+        expected: usize,
+        /// The key length that was actually passed in.
+        actual: usize,
+    },
+    /// [`Environment::open_database`][crate::Environment::open_database] was
+    /// called with a [`Comparator`][crate::Comparator] that doesn't match the
+    /// one the database was actually opened with, typically because two
+    /// callers raced to open the same not-yet-cached name with different
+    /// comparators.
+    ComparatorMismatch,
+}
+
+impl Error {
+    #[inline]
+    #[must_use]
+    pub(crate) fn from_raw(code: i32) -> Self {
+        match code {
+            sys::MDB_KEYEXIST => Self::KeyExist,
+            sys::MDB_NOTFOUND => Self::NotFound,
+            sys::MDB_PAGE_NOTFOUND => Self::PageNotFound,
+            sys::MDB_CORRUPTED => Self::Corrupted,
+            sys::MDB_MAP_FULL => Self::MapFull,
+            sys::MDB_MAP_RESIZED => Self::MapResized,
+            sys::MDB_TXN_FULL => Self::TxnFull,
+            sys::MDB_READERS_FULL => Self::ReadersFull,
+            sys::MDB_DBS_FULL => Self::DbsFull,
+            sys::MDB_INVALID => Self::Invalid,
+            other => Self::Other(other),
+        }
+    }
+}
 
-impl Debug for Error {
+impl Display for Error {
     #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Self::InvalidKeyLength { expected, actual } = *self {
+            return write!(f, "invalid key length: expected {expected}, got {actual}");
+        }
+        if let Self::ComparatorMismatch = *self {
+            return write!(f, "comparator does not match the one the database was opened with");
+        }
+        let code = match *self {
+            Self::KeyExist => sys::MDB_KEYEXIST,
+            Self::NotFound => sys::MDB_NOTFOUND,
+            Self::PageNotFound => sys::MDB_PAGE_NOTFOUND,
+            Self::Corrupted => sys::MDB_CORRUPTED,
+            Self::MapFull => sys::MDB_MAP_FULL,
+            Self::MapResized => sys::MDB_MAP_RESIZED,
+            Self::TxnFull => sys::MDB_TXN_FULL,
+            Self::ReadersFull => sys::MDB_READERS_FULL,
+            Self::DbsFull => sys::MDB_DBS_FULL,
+            Self::Invalid => sys::MDB_INVALID,
+            Self::Other(code) => code,
+            Self::InvalidKeyLength { .. } | Self::ComparatorMismatch => unreachable!("handled above"),
+        };
         // SAFETY: [`sys::mdb_strerror`] always returns a valid pointer
-        let str = unsafe { CStr::from_ptr(sys::mdb_strerror(self.0)) };
-        Debug::fmt(str, f)
+        let str = unsafe { CStr::from_ptr(sys::mdb_strerror(code)) };
+        Display::fmt(&str.to_string_lossy(), f)
     }
 }
 
+impl std::error::Error for Error {}
+
 pub type Result<T> = std::result::Result<T, Error>;
 
 pub mod environment {
     use std::{
+        cell::Cell,
+        collections::HashMap,
         ffi::CString,
         mem::MaybeUninit,
-        ptr::{null, null_mut},
-        sync::Arc,
+        ptr::null_mut,
+        sync::{Arc, Mutex},
     };
 
     use crate::{
+        comparator::Comparator,
+        database::Database,
+        info::EnvInfo,
         sys,
         transaction::{self, Transaction},
         Error, Result,
@@ -75,9 +167,9 @@ pub mod environment {
 
     pub struct Environment {
         env: *mut sys::MDB_env,
-        // Eagerly open a database during [`Environment`] construction and keep it here to avoid
-        // dealing with LMDB's constraints.
-        pub(crate) dbi: sys::MDB_dbi,
+        // LMDB forbids calling `mdb_dbi_open` for the same name from two transactions at
+        // once, so handles are opened lazily and cached here for reuse.
+        dbs: Mutex<HashMap<String, Database>>,
     }
 
     // SAFETY: LMDB environment is thread-safe
@@ -95,6 +187,8 @@ pub mod environment {
         /// * `flags` - special options for this environment.
         /// * `map_size` - the size of the memory map to use for this
         ///   environment.
+        /// * `max_dbs` - the maximum number of named databases that may be
+        ///   opened via [`open_database`][Self::open_database].
         /// * `mode` - the UNIX permissions to set on created files and
         ///   semaphores. This parameter is ignored on Windows.
         ///
@@ -110,57 +204,43 @@ pub mod environment {
         /// [1]: http://www.lmdb.tech/doc/group__mdb.html#ga32a193c6bf4d7d5c5d579e71f22e9340
         /// [2]: http://www.lmdb.tech/doc/group__mdb.html#ga4366c43ada8874588b6a62fbda2d1e95
         #[inline]
-        pub fn open(path: &str, flags: Flags, map_size: usize, mode: u32) -> Result<Self> {
+        pub fn open(
+            path: &str,
+            flags: Flags,
+            map_size: usize,
+            max_dbs: u32,
+            mode: u32,
+        ) -> Result<Self> {
             let mut env = MaybeUninit::uninit();
             // SAFETY: the ffi call is immediately followed by an error check
             let r = unsafe { sys::mdb_env_create(env.as_mut_ptr()) };
             if r != 0 {
-                return Err(Error(r));
+                return Err(Error::from_raw(r));
             }
             // SAFETY: should have been initialized by the [`sys::mdb_env_create`] call
             let env = unsafe { env.assume_init() };
             // SAFETY: the ffi call is immediately followed by an error check
             let r = unsafe { sys::mdb_env_set_mapsize(env, map_size) };
             if r != 0 {
-                return Err(Error(r));
-            }
-            let path = CString::new(path).expect("invalid `path` value");
-            // SAFETY: the ffi call is immediately followed by an error check
-            let r = unsafe { sys::mdb_env_open(env, path.as_ptr(), flags.bits(), mode) };
-            if r != 0 {
-                // SAFETY: `env` is not used after this call, so it's safe to close it
-                unsafe { sys::mdb_env_close(env) };
-                return Err(Error(r));
-            }
-            let mut txn = MaybeUninit::uninit();
-            // SAFETY: the ffi call is immediately followed by an error check
-            let r =
-                unsafe { sys::mdb_txn_begin(env, null_mut(), sys::MDB_RDONLY, txn.as_mut_ptr()) };
-            if r != 0 {
-                // SAFETY: `env` is not used after this call, so it's safe to close it
-                unsafe { sys::mdb_env_close(env) };
-                return Err(Error(r));
+                return Err(Error::from_raw(r));
             }
-            // SAFETY: should have been initialized by the [`sys::mdb_txn_begin`] call
-            let txn = unsafe { txn.assume_init() };
-            let mut dbi = MaybeUninit::uninit();
             // SAFETY: the ffi call is immediately followed by an error check
-            let r = unsafe { sys::mdb_dbi_open(txn, null(), 0, dbi.as_mut_ptr()) };
+            let r = unsafe { sys::mdb_env_set_maxdbs(env, max_dbs) };
             if r != 0 {
-                // SAFETY: `env` is not used after this call, so it's safe to close it
-                unsafe { sys::mdb_env_close(env) };
-                return Err(Error(r));
+                return Err(Error::from_raw(r));
             }
-            // SAFETY: should have been initialized by the [`sys::mdb_dbi_open`] call
-            let dbi = unsafe { dbi.assume_init() };
+            let path = CString::new(path).expect("invalid `path` value");
             // SAFETY: the ffi call is immediately followed by an error check
-            let r = unsafe { sys::mdb_txn_commit(txn) };
+            let r = unsafe { sys::mdb_env_open(env, path.as_ptr(), flags.bits(), mode) };
             if r != 0 {
                 // SAFETY: `env` is not used after this call, so it's safe to close it
                 unsafe { sys::mdb_env_close(env) };
-                return Err(Error(r));
+                return Err(Error::from_raw(r));
             }
-            Ok(Self { env, dbi })
+            Ok(Self {
+                env,
+                dbs: Mutex::new(HashMap::new()),
+            })
         }
 
         /// [`Creates`][0] a transaction with specified
@@ -188,6 +268,119 @@ pub mod environment {
             Transaction::begin(self.clone(), flags)
         }
 
+        /// [`Opens`][0] the named database `name`, creating it if `create` is
+        /// `true` and it doesn't exist yet. The returned [`Database`] handle is
+        /// cached, so subsequent calls with the same `name` are cheap and do
+        /// not re-enter LMDB.
+        ///
+        /// # Errors
+        ///
+        /// * Returns an [`Error`] if any call to LMDB API fails.
+        /// * Returns [`Error::ComparatorMismatch`] if `name` is already cached
+        ///   under a different `comparator` than the one passed here, e.g.
+        ///   because another call raced this one to open it first.
+        ///
+        /// # Panics
+        ///
+        /// * Panics if `name` contains a null byte.
+        /// * Panics if `name` is not cached yet and this thread already holds an
+        ///   open [`Transaction`], since opening an uncached database begins its
+        ///   own top-level transaction,
+        ///   and LMDB permits only one transaction per thread at a time,
+        ///   read-only or not.
+        ///
+        /// [0]: http://www.lmdb.tech/doc/group__mdb.html#gac08cad5b096925642ca359a6d6f0562a
+        #[inline]
+        pub fn open_database(
+            &self,
+            name: &str,
+            create: bool,
+            comparator: Comparator,
+        ) -> Result<Database> {
+            let mut dbs = self.dbs.lock().unwrap();
+            if let Some(&db) = dbs.get(name) {
+                if comparator.key_len() != db.key_len {
+                    return Err(Error::ComparatorMismatch);
+                }
+                return Ok(db);
+            }
+            // LMDB allows only one transaction per thread, read-only or not, so
+            // this internal transaction must not be opened behind the back of a
+            // `Transaction` the caller already holds on this thread.
+            assert!(
+                !transaction::ACTIVE_TXN.with(Cell::get),
+                "cannot open a database while this thread holds an active transaction"
+            );
+            let txn_flags = if create { 0 } else { sys::MDB_RDONLY };
+            let mut txn = MaybeUninit::uninit();
+            // SAFETY: the ffi call is immediately followed by an error check
+            let r =
+                unsafe { sys::mdb_txn_begin(self.env, null_mut(), txn_flags, txn.as_mut_ptr()) };
+            if r != 0 {
+                return Err(Error::from_raw(r));
+            }
+            // SAFETY: should have been initialized by the [`sys::mdb_txn_begin`] call
+            let txn = unsafe { txn.assume_init() };
+            let name_c = CString::new(name).expect("invalid `name` value");
+            let flags = if create { sys::MDB_CREATE } else { 0 };
+            let mut dbi = MaybeUninit::uninit();
+            // SAFETY: the ffi call is immediately followed by an error check
+            let r = unsafe { sys::mdb_dbi_open(txn, name_c.as_ptr(), flags, dbi.as_mut_ptr()) };
+            if r != 0 {
+                // SAFETY: `txn` is not used after this call, so it's safe to abort it
+                unsafe { sys::mdb_txn_abort(txn) };
+                return Err(Error::from_raw(r));
+            }
+            // SAFETY: should have been initialized by the [`sys::mdb_dbi_open`] call
+            let dbi = unsafe { dbi.assume_init() };
+            let cmp = comparator.mdb_cmp_func();
+            if cmp.is_some() {
+                // SAFETY: the ffi call is immediately followed by an error check
+                let r = unsafe { sys::mdb_set_compare(txn, dbi, cmp) };
+                if r != 0 {
+                    // SAFETY: `txn` is not used after this call, so it's safe to abort it
+                    unsafe { sys::mdb_txn_abort(txn) };
+                    return Err(Error::from_raw(r));
+                }
+            }
+            // SAFETY: the ffi call is immediately followed by an error check
+            let r = unsafe { sys::mdb_txn_commit(txn) };
+            if r != 0 {
+                return Err(Error::from_raw(r));
+            }
+            let db = Database::new(dbi, comparator.key_len());
+            dbs.insert(name.to_owned(), db);
+            Ok(db)
+        }
+
+        /// [`Retrieves`][0] information about this environment, such as its
+        /// map size, last used page, and reader slot usage. Comparing
+        /// [`EnvInfo::last_pgno`] against [`EnvInfo::map_size`] lets callers
+        /// detect an impending `MDB_MAP_FULL` and proactively grow the map.
+        ///
+        /// This only wraps `mdb_env_info`, not `mdb_env_stat`: every field
+        /// `mdb_env_stat` reports is per-database (page counts, tree depth,
+        /// entry count), so it's exposed per-database instead, as
+        /// [`Transaction::stat`][crate::Transaction::stat].
+        ///
+        /// # Errors
+        ///
+        /// * Returns an [`Error`] if any call to LMDB API fails.
+        ///
+        /// [0]: http://www.lmdb.tech/doc/group__mdb.html#gaf881dca452050efbd434cd16e4bae255
+        #[inline]
+        pub fn info(&self) -> Result<EnvInfo> {
+            let mut info = MaybeUninit::uninit();
+            // SAFETY: the ffi call is immediately followed by an error check
+            let r = unsafe { sys::mdb_env_info(self.env, info.as_mut_ptr()) };
+            if r != 0 {
+                return Err(Error::from_raw(r));
+            }
+            // SAFETY: should have been initialized by the [`sys::mdb_env_info`] call
+            let info = unsafe { info.assume_init() };
+            Ok(EnvInfo::from_raw(info))
+        }
+
         #[inline]
         #[must_use]
         pub(crate) fn as_raw_ptr(&self) -> *mut sys::MDB_env {
@@ -205,16 +398,228 @@ pub mod environment {
     }
 }
 
+pub mod comparator {
+    use std::{cmp::Ordering, os::raw::c_int};
+
+    use crate::sys;
+
+    /// Selects how keys are ordered within a [`Database`][crate::Database].
+    ///
+    /// LMDB sorts keys bytewise by default, which is usually not what's wanted
+    /// for integer or fixed-width hash keys. The comparator is installed once,
+    /// when the database is first opened, and applies for its entire lifetime.
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+    pub enum Comparator {
+        /// LMDB's default bytewise comparison.
+        #[default]
+        Bytewise,
+        /// Compares keys as native-endian `u64` integers.
+        U64,
+        /// Compares 32-byte keys as eight `u32` limbs, most significant first.
+        FixedWidth32,
+    }
+
+    impl Comparator {
+        #[inline]
+        #[must_use]
+        pub(crate) fn mdb_cmp_func(self) -> sys::MDB_cmp_func {
+            match self {
+                Self::Bytewise => None,
+                Self::U64 => Some(compare_u64),
+                Self::FixedWidth32 => Some(compare_fixed_width_32),
+            }
+        }
+
+        /// The exact key length this comparator requires, if any.
+        ///
+        /// [`compare_u64`] and [`compare_fixed_width_32`] read a fixed number of
+        /// bytes out of each key without bounds checking, relying on LMDB to only
+        /// ever hand them keys of the expected length. The safe API enforces that
+        /// by rejecting keys of any other length before they reach LMDB.
+        #[inline]
+        #[must_use]
+        pub(crate) fn key_len(self) -> Option<usize> {
+            match self {
+                Self::Bytewise => None,
+                Self::U64 => Some(std::mem::size_of::<u64>()),
+                Self::FixedWidth32 => Some(32),
+            }
+        }
+    }
+
+    /// Compares two keys as native-endian `u64` integers.
+    ///
+    /// # Safety
+    ///
+    /// `a` and `b` must point to valid [`sys::MDB_val`]s whose `mv_data`
+    /// points to at least 8 readable bytes, which LMDB guarantees when this
+    /// comparator is registered for a `u64`-keyed database.
+    unsafe extern "C" fn compare_u64(a: *const sys::MDB_val, b: *const sys::MDB_val) -> c_int {
+        // SAFETY: caller (LMDB) guarantees `a` and `b` point to valid `MDB_val`s whose
+        // `mv_data` is at least 8 bytes, per this comparator's documented contract
+        let (a, b) = unsafe { (*a, *b) };
+        // SAFETY: see above
+        let a = unsafe { a.mv_data.cast::<u64>().read_unaligned() };
+        // SAFETY: see above
+        let b = unsafe { b.mv_data.cast::<u64>().read_unaligned() };
+        ordering_to_c_int(a.cmp(&b))
+    }
+
+    /// Compares two 32-byte keys as eight `u32` limbs, most significant first.
+    ///
+    /// # Safety
+    ///
+    /// `a` and `b` must point to valid [`sys::MDB_val`]s whose `mv_data`
+    /// points to at least 32 readable bytes, which LMDB guarantees when this
+    /// comparator is registered for a database keyed by such fixed-width
+    /// values.
+    unsafe extern "C" fn compare_fixed_width_32(
+        a: *const sys::MDB_val,
+        b: *const sys::MDB_val,
+    ) -> c_int {
+        // SAFETY: caller (LMDB) guarantees `a` and `b` point to valid `MDB_val`s whose
+        // `mv_data` is at least 32 bytes, per this comparator's documented contract
+        let (a, b) = unsafe { (*a, *b) };
+        let a = a.mv_data.cast::<u32>();
+        let b = b.mv_data.cast::<u32>();
+        for i in (0..=7).rev() {
+            // SAFETY: see above
+            let (la, lb) = unsafe { (a.add(i).read_unaligned(), b.add(i).read_unaligned()) };
+            match la.cmp(&lb) {
+                Ordering::Equal => continue,
+                ordering => return ordering_to_c_int(ordering),
+            }
+        }
+        0
+    }
+
+    #[inline]
+    #[must_use]
+    fn ordering_to_c_int(ordering: Ordering) -> c_int {
+        match ordering {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        }
+    }
+}
+
+pub mod database {
+    use crate::{sys, Error, Result};
+
+    /// A handle to a named sub-database within an [`Environment`][crate::Environment],
+    /// obtained via [`Environment::open_database`][crate::Environment::open_database].
+    #[derive(Copy, Clone, Debug)]
+    pub struct Database {
+        pub(crate) dbi: sys::MDB_dbi,
+        pub(crate) key_len: Option<usize>,
+    }
+
+    impl Database {
+        #[inline]
+        pub(crate) fn new(dbi: sys::MDB_dbi, key_len: Option<usize>) -> Self {
+            Self { dbi, key_len }
+        }
+
+        /// Checks that `key` is a valid length for this database's comparator,
+        /// returning [`Error::InvalidKeyLength`] if not.
+        ///
+        /// This must be called before any key is handed to LMDB, since the
+        /// `U64` and `FixedWidth32` comparators read a fixed number of bytes
+        /// out of each key without bounds checking.
+        #[inline]
+        pub(crate) fn check_key_len(&self, key: &[u8]) -> Result<()> {
+            match self.key_len {
+                Some(expected) if expected != key.len() => Err(Error::InvalidKeyLength {
+                    expected,
+                    actual: key.len(),
+                }),
+                _ => Ok(()),
+            }
+        }
+    }
+}
+
+pub mod stat {
+    use crate::sys;
+
+    /// Statistics for a single database, as returned by
+    /// [`Transaction::stat`][crate::Transaction::stat].
+    #[derive(Copy, Clone, Debug)]
+    pub struct Stat {
+        /// Size of a database page.
+        pub page_size: u32,
+        /// Depth (height) of the B-tree.
+        pub depth: u32,
+        /// Number of internal (non-leaf) pages.
+        pub branch_pages: usize,
+        /// Number of leaf pages.
+        pub leaf_pages: usize,
+        /// Number of overflow pages.
+        pub overflow_pages: usize,
+        /// Number of data items.
+        pub entries: usize,
+    }
+
+    impl Stat {
+        #[inline]
+        pub(crate) fn from_raw(stat: sys::MDB_stat) -> Self {
+            Self {
+                page_size: stat.ms_psize,
+                depth: stat.ms_depth,
+                branch_pages: stat.ms_branch_pages,
+                leaf_pages: stat.ms_leaf_pages,
+                overflow_pages: stat.ms_overflow_pages,
+                entries: stat.ms_entries,
+            }
+        }
+    }
+}
+
+pub mod info {
+    use crate::sys;
+
+    /// Information about an [`Environment`][crate::Environment], as returned
+    /// by [`Environment::info`][crate::Environment::info].
+    #[derive(Copy, Clone, Debug)]
+    pub struct EnvInfo {
+        /// Size of the memory map.
+        pub map_size: usize,
+        /// ID of the last used page.
+        pub last_pgno: usize,
+        /// ID of the last committed transaction.
+        pub last_txn_id: usize,
+        /// Maximum number of reader slots in the environment.
+        pub max_readers: u32,
+        /// Number of reader slots currently in use.
+        pub num_readers: u32,
+    }
+
+    impl EnvInfo {
+        #[inline]
+        pub(crate) fn from_raw(info: sys::MDB_envinfo) -> Self {
+            Self {
+                map_size: info.me_mapsize,
+                last_pgno: info.me_last_pgno,
+                last_txn_id: info.me_last_txnid,
+                max_readers: info.me_maxreaders,
+                num_readers: info.me_numreaders,
+            }
+        }
+    }
+}
+
 pub mod transaction {
     use std::{
         cell::Cell,
+        marker::PhantomData,
         mem::MaybeUninit,
-        ptr::{addr_of, null_mut},
+        ptr::{addr_of, addr_of_mut, null_mut},
         slice,
         sync::Arc,
     };
 
-    use crate::{environment::Environment, sys, Error, Result};
+    use crate::{database::Database, environment::Environment, stat::Stat, sys, Error, Result};
 
     pub struct DataView<'a> {
         /// We hold a shared reference to [`Transaction`] and thus guarantee
@@ -224,10 +629,10 @@ pub mod transaction {
         /// ```compile_fail
         /// use std::sync::Arc;
         ///
-        /// fn test(env: Arc<litemdb::Environment>) -> litemdb::Result<()> {
+        /// fn test(env: Arc<litemdb::Environment>, db: litemdb::Database) -> litemdb::Result<()> {
         ///     let mut txn = env.begin_transaction(litemdb::transaction::Flags::empty())?;
-        ///     if let Some(view) = txn.get(b"key")? {
-        ///         txn.del(b"key")?;
+        ///     if let Some(view) = txn.get(&db, b"key")? {
+        ///         txn.del(&db, b"key")?;
         ///         // compiler error
         ///         println!("{:?}", view.as_ref());
         ///     }
@@ -238,6 +643,13 @@ pub mod transaction {
         data: &'a [u8],
     }
 
+    impl<'a> DataView<'a> {
+        #[inline]
+        pub(crate) fn new(txn: &'a Transaction, data: &'a [u8]) -> Self {
+            Self { _txn: txn, data }
+        }
+    }
+
     impl<'a> AsRef<[u8]> for DataView<'a> {
         #[inline]
         #[must_use]
@@ -254,8 +666,22 @@ pub mod transaction {
         }
     }
 
+    bitflags::bitflags! {
+        #[derive(Copy, Clone, Debug)]
+        pub struct WriteFlags: u32 {
+            /// Don't write if the key already exists.
+            const NO_OVERWRITE = sys::MDB_NOOVERWRITE;
+            /// Append the given key/data pair to the end of the database. No
+            /// key comparison is performed; this is for bulk loading
+            /// pre-sorted data and appending a key out of order will corrupt
+            /// the database.
+            const APPEND = sys::MDB_APPEND;
+        }
+    }
+
     thread_local! {
-        static ACTIVE_TXN: Cell<bool> = const { Cell::new(false) };
+        pub(crate) static ACTIVE_TXN: Cell<bool> = const { Cell::new(false) };
+        static NESTED_TXN: Cell<bool> = const { Cell::new(false) };
     }
 
     pub struct Transaction {
@@ -263,6 +689,7 @@ pub mod transaction {
         // environment from closing.
         env: Arc<Environment>,
         is_discarded: bool,
+        is_nested: bool,
         txn: *mut sys::MDB_txn,
     }
 
@@ -281,7 +708,7 @@ pub mod transaction {
                 sys::mdb_txn_begin(env.as_raw_ptr(), null_mut(), flags.bits(), txn.as_mut_ptr())
             };
             if r != 0 {
-                return Err(Error(r));
+                return Err(Error::from_raw(r));
             }
             // SAFETY: should have been initialized by the [`sys::mdb_txn_begin`] call
             let txn = unsafe { txn.assume_init() };
@@ -289,6 +716,58 @@ pub mod transaction {
                 env,
                 txn,
                 is_discarded: false,
+                is_nested: false,
+            })
+        }
+
+        /// [`Begins`][0] a nested transaction with `self` as its parent. If the
+        /// returned [`NestedTransaction`] is [`committed`][1], its writes are
+        /// merged into `self`; if it's [`aborted`][2] (or dropped without
+        /// being resolved), only its writes are discarded and `self` is left
+        /// untouched. Borrowing `self` for as long as the nested transaction
+        /// is alive statically prevents `self` from being used until it is
+        /// resolved, matching LMDB's requirement that a parent transaction
+        /// must not be touched while a child is active.
+        ///
+        /// # Errors
+        ///
+        /// * Returns an [`Error`] if any call to LMDB API fails.
+        ///
+        /// # Panics
+        ///
+        /// * Panics if `self` already has another open nested transaction.
+        ///
+        /// [0]: http://www.lmdb.tech/doc/group__internal.html#gaec09fc4062fc4d99882f7f7256570bdb
+        /// [1]: NestedTransaction::commit
+        /// [2]: NestedTransaction::abort
+        #[inline]
+        pub fn begin_nested(&mut self, flags: Flags) -> Result<NestedTransaction<'_>> {
+            NESTED_TXN.with(|cell| {
+                assert!(
+                    !cell.replace(true),
+                    "A transaction may only have a single nested transaction at a time."
+                );
+            });
+            let mut txn = MaybeUninit::uninit();
+            // SAFETY: the ffi call is immediately followed by an error check
+            let r = unsafe {
+                sys::mdb_txn_begin(self.env.as_raw_ptr(), self.txn, flags.bits(), txn.as_mut_ptr())
+            };
+            if r != 0 {
+                // No `NestedTransaction` was created, so nothing will reset this on drop.
+                NESTED_TXN.with(|cell| cell.set(false));
+                return Err(Error::from_raw(r));
+            }
+            // SAFETY: should have been initialized by the [`sys::mdb_txn_begin`] call
+            let txn = unsafe { txn.assume_init() };
+            Ok(NestedTransaction {
+                txn: Self {
+                    env: self.env.clone(),
+                    txn,
+                    is_discarded: false,
+                    is_nested: true,
+                },
+                _parent: PhantomData,
             })
         }
 
@@ -300,7 +779,8 @@ pub mod transaction {
         ///
         /// [0]: http://www.lmdb.tech/doc/group__mdb.html#ga8bf10cd91d3f3a83a34d04ce6b07992d
         #[inline]
-        pub fn get(&self, key: &[u8]) -> Result<Option<DataView>> {
+        pub fn get(&self, db: &Database, key: &[u8]) -> Result<Option<DataView>> {
+            db.check_key_len(key)?;
             let key = sys::MDB_val {
                 mv_data: key.as_ptr().cast_mut().cast(),
                 mv_size: key.len(),
@@ -310,7 +790,7 @@ pub mod transaction {
             let r = unsafe {
                 sys::mdb_get(
                     self.as_raw_ptr(),
-                    self.env.dbi,
+                    db.dbi,
                     addr_of!(key).cast_mut(),
                     data.as_mut_ptr(),
                 )
@@ -319,7 +799,7 @@ pub mod transaction {
                 return Ok(None);
             }
             if r != 0 {
-                return Err(Error(r));
+                return Err(Error::from_raw(r));
             }
             // SAFETY: should have been initialized by the [`sys::mdb_get`] call
             let data = unsafe { data.assume_init() };
@@ -329,7 +809,12 @@ pub mod transaction {
             Ok(Some(DataView { _txn: self, data }))
         }
 
-        /// [`Puts`][0] an item into database.
+        /// [`Puts`][0] an item into database. `flags` may be used to request
+        /// insert-only semantics or a bulk-append optimization; see
+        /// [`WriteFlags`].
+        ///
+        /// Returns `false` instead of an error if [`WriteFlags::NO_OVERWRITE`]
+        /// was given and `key` already exists.
         ///
         /// # Errors
         ///
@@ -337,7 +822,14 @@ pub mod transaction {
         ///
         /// [0]: http://www.lmdb.tech/doc/group__mdb.html#ga4fa8573d9236d54687c61827ebf8cac0
         #[inline]
-        pub fn put(&mut self, key: &[u8], data: &[u8]) -> Result<()> {
+        pub fn put(
+            &mut self,
+            db: &Database,
+            key: &[u8],
+            data: &[u8],
+            flags: WriteFlags,
+        ) -> Result<bool> {
+            db.check_key_len(key)?;
             let key = sys::MDB_val {
                 mv_data: key.as_ptr().cast_mut().cast(),
                 mv_size: key.len(),
@@ -350,16 +842,59 @@ pub mod transaction {
             let r = unsafe {
                 sys::mdb_put(
                     self.as_raw_ptr(),
-                    self.env.dbi,
+                    db.dbi,
                     addr_of!(key).cast_mut(),
                     addr_of!(data).cast_mut(),
-                    0,
+                    flags.bits(),
                 )
             };
+            if r == sys::MDB_KEYEXIST {
+                return Ok(false);
+            }
             if r != 0 {
-                return Err(Error(r));
+                return Err(Error::from_raw(r));
             }
-            Ok(())
+            Ok(true)
+        }
+
+        /// [`Reserves`][0] `len` bytes for `key` in the database and returns a
+        /// mutable slice for the caller to fill in place, avoiding a second
+        /// copy of the value for large writes.
+        ///
+        /// # Errors
+        ///
+        /// * Returns an [`Error`] if any call to LMDB API fails.
+        ///
+        /// [0]: http://www.lmdb.tech/doc/group__mdb.html#ga4fa8573d9236d54687c61827ebf8cac0
+        #[inline]
+        pub fn reserve(&mut self, db: &Database, key: &[u8], len: usize) -> Result<&mut [u8]> {
+            db.check_key_len(key)?;
+            let key = sys::MDB_val {
+                mv_data: key.as_ptr().cast_mut().cast(),
+                mv_size: key.len(),
+            };
+            let mut data = sys::MDB_val {
+                mv_data: null_mut(),
+                mv_size: len,
+            };
+            // SAFETY: the ffi call is immediately followed by an error check
+            let r = unsafe {
+                sys::mdb_put(
+                    self.as_raw_ptr(),
+                    db.dbi,
+                    addr_of!(key).cast_mut(),
+                    addr_of_mut!(data),
+                    sys::MDB_RESERVE,
+                )
+            };
+            if r != 0 {
+                return Err(Error::from_raw(r));
+            }
+            // SAFETY: `mdb_put` with `MDB_RESERVE` allocated `len` writable bytes in the
+            // map and pointed `data.mv_data` at them; they remain valid until a
+            // subsequent update operation, or the end of the transaction
+            let data = unsafe { slice::from_raw_parts_mut(data.mv_data.cast(), data.mv_size) };
+            Ok(data)
         }
 
         /// [`Deletes`][0] an item from database.
@@ -370,7 +905,8 @@ pub mod transaction {
         ///
         /// [0]: http://www.lmdb.tech/doc/group__mdb.html#gab8182f9360ea69ac0afd4a4eaab1ddb0
         #[inline]
-        pub fn del(&mut self, key: &[u8]) -> Result<bool> {
+        pub fn del(&mut self, db: &Database, key: &[u8]) -> Result<bool> {
+            db.check_key_len(key)?;
             let key = sys::MDB_val {
                 mv_data: key.as_ptr().cast_mut().cast(),
                 mv_size: key.len(),
@@ -379,7 +915,7 @@ pub mod transaction {
             let r = unsafe {
                 sys::mdb_del(
                     self.as_raw_ptr(),
-                    self.env.dbi,
+                    db.dbi,
                     addr_of!(key).cast_mut(),
                     null_mut(),
                 )
@@ -388,11 +924,45 @@ pub mod transaction {
                 return Ok(false);
             }
             if r != 0 {
-                return Err(Error(r));
+                return Err(Error::from_raw(r));
             }
             Ok(true)
         }
 
+        /// [`Opens`][0] a [`Cursor`][crate::Cursor] over this database, positioned
+        /// before the first key.
+        ///
+        /// # Errors
+        ///
+        /// * Returns an [`Error`] if any call to LMDB API fails.
+        ///
+        /// [0]: http://www.lmdb.tech/doc/group__mdb.html#ga9ff5d7bd42557fd5ee235dc1d62613aa
+        #[inline]
+        pub fn cursor(&self, db: &Database) -> Result<crate::Cursor> {
+            crate::cursor::Cursor::open(self, db)
+        }
+
+        /// [`Retrieves`][0] statistics for `db`, such as its B-tree depth and
+        /// entry count.
+        ///
+        /// # Errors
+        ///
+        /// * Returns an [`Error`] if any call to LMDB API fails.
+        ///
+        /// [0]: http://www.lmdb.tech/doc/group__mdb.html#gae6c1069febe92a0767f7e0791d8c6e01
+        #[inline]
+        pub fn stat(&self, db: &Database) -> Result<Stat> {
+            let mut stat = MaybeUninit::uninit();
+            // SAFETY: the ffi call is immediately followed by an error check
+            let r = unsafe { sys::mdb_stat(self.as_raw_ptr(), db.dbi, stat.as_mut_ptr()) };
+            if r != 0 {
+                return Err(Error::from_raw(r));
+            }
+            // SAFETY: should have been initialized by the [`sys::mdb_stat`] call
+            let stat = unsafe { stat.assume_init() };
+            Ok(Stat::from_raw(stat))
+        }
+
         /// [`Aborts`][0] all operations of this [`Transaction`] instead of
         /// saving them.
         ///
@@ -417,7 +987,7 @@ pub mod transaction {
             // SAFETY: the ffi call is immediately followed by an error check
             let r = unsafe { sys::mdb_txn_commit(self.as_raw_ptr()) };
             if r != 0 {
-                return Err(Error(r));
+                return Err(Error::from_raw(r));
             }
             self.is_discarded = true;
             Ok(())
@@ -433,7 +1003,11 @@ pub mod transaction {
     impl Drop for Transaction {
         #[inline]
         fn drop(&mut self) {
-            ACTIVE_TXN.with(|cell| assert!(cell.replace(false)));
+            if self.is_nested {
+                NESTED_TXN.with(|cell| assert!(cell.replace(false)));
+            } else {
+                ACTIVE_TXN.with(|cell| assert!(cell.replace(false)));
+            }
             if !self.is_discarded {
                 // SAFETY: this [`Transaction`] will not be used after this call so it's safe to
                 // abort it
@@ -441,4 +1015,303 @@ pub mod transaction {
             }
         }
     }
+
+    /// A [`Transaction`] nested inside a parent transaction, created via
+    /// [`Transaction::begin_nested`].
+    pub struct NestedTransaction<'p> {
+        txn: Transaction,
+        _parent: PhantomData<&'p mut Transaction>,
+    }
+
+    impl<'p> NestedTransaction<'p> {
+        /// See [`Transaction::get`].
+        ///
+        /// # Errors
+        ///
+        /// * Returns an [`Error`] if any call to LMDB API fails.
+        #[inline]
+        pub fn get(&self, db: &Database, key: &[u8]) -> Result<Option<DataView>> {
+            self.txn.get(db, key)
+        }
+
+        /// See [`Transaction::put`].
+        ///
+        /// # Errors
+        ///
+        /// * Returns an [`Error`] if any call to LMDB API fails.
+        #[inline]
+        pub fn put(
+            &mut self,
+            db: &Database,
+            key: &[u8],
+            data: &[u8],
+            flags: WriteFlags,
+        ) -> Result<bool> {
+            self.txn.put(db, key, data, flags)
+        }
+
+        /// See [`Transaction::del`].
+        ///
+        /// # Errors
+        ///
+        /// * Returns an [`Error`] if any call to LMDB API fails.
+        #[inline]
+        pub fn del(&mut self, db: &Database, key: &[u8]) -> Result<bool> {
+            self.txn.del(db, key)
+        }
+
+        /// See [`Transaction::reserve`].
+        ///
+        /// # Errors
+        ///
+        /// * Returns an [`Error`] if any call to LMDB API fails.
+        #[inline]
+        pub fn reserve(&mut self, db: &Database, key: &[u8], len: usize) -> Result<&mut [u8]> {
+            self.txn.reserve(db, key, len)
+        }
+
+        /// See [`Transaction::cursor`].
+        ///
+        /// # Errors
+        ///
+        /// * Returns an [`Error`] if any call to LMDB API fails.
+        #[inline]
+        pub fn cursor(&self, db: &Database) -> Result<crate::Cursor> {
+            self.txn.cursor(db)
+        }
+
+        /// See [`Transaction::stat`].
+        ///
+        /// # Errors
+        ///
+        /// * Returns an [`Error`] if any call to LMDB API fails.
+        #[inline]
+        pub fn stat(&self, db: &Database) -> Result<Stat> {
+            self.txn.stat(db)
+        }
+
+        /// See [`Transaction::abort`].
+        #[inline]
+        pub fn abort(self) {
+            self.txn.abort();
+        }
+
+        /// [`Commits`][0] this nested transaction, merging its writes into its
+        /// parent. The parent transaction is left open and must still be
+        /// separately committed or aborted.
+        ///
+        /// # Errors
+        ///
+        /// * Returns an [`Error`] if any call to LMDB API fails.
+        ///
+        /// [0]: http://www.lmdb.tech/doc/group__internal.html#ga846fbd6f46105617ac9f4d76476f6597
+        #[inline]
+        pub fn commit(self) -> Result<()> {
+            self.txn.commit()
+        }
+    }
+}
+
+pub mod cursor {
+    use std::{
+        mem::MaybeUninit,
+        ptr::{addr_of_mut, null_mut},
+        slice,
+    };
+
+    use crate::{
+        database::Database,
+        sys,
+        transaction::{DataView, Transaction},
+        Error, Result,
+    };
+
+    pub struct Cursor<'a> {
+        txn: &'a Transaction,
+        cursor: *mut sys::MDB_cursor,
+        db: Database,
+    }
+
+    impl<'a> Cursor<'a> {
+        #[inline]
+        pub(crate) fn open(txn: &'a Transaction, db: &Database) -> Result<Self> {
+            let mut cursor = MaybeUninit::uninit();
+            // SAFETY: the ffi call is immediately followed by an error check
+            let r =
+                unsafe { sys::mdb_cursor_open(txn.as_raw_ptr(), db.dbi, cursor.as_mut_ptr()) };
+            if r != 0 {
+                return Err(Error::from_raw(r));
+            }
+            // SAFETY: should have been initialized by the [`sys::mdb_cursor_open`] call
+            let cursor = unsafe { cursor.assume_init() };
+            Ok(Self { txn, cursor, db: *db })
+        }
+
+        fn get(
+            &mut self,
+            key: Option<&[u8]>,
+            op: sys::MDB_cursor_op,
+        ) -> Result<Option<(DataView<'a>, DataView<'a>)>> {
+            let mut key = match key {
+                Some(key) => sys::MDB_val {
+                    mv_data: key.as_ptr().cast_mut().cast(),
+                    mv_size: key.len(),
+                },
+                None => sys::MDB_val {
+                    mv_data: null_mut(),
+                    mv_size: 0,
+                },
+            };
+            let mut data = MaybeUninit::uninit();
+            // SAFETY: the ffi call is immediately followed by an error check
+            let r = unsafe {
+                sys::mdb_cursor_get(self.cursor, addr_of_mut!(key), data.as_mut_ptr(), op)
+            };
+            if r == sys::MDB_NOTFOUND {
+                return Ok(None);
+            }
+            if r != 0 {
+                return Err(Error::from_raw(r));
+            }
+            // SAFETY: should have been initialized by the [`sys::mdb_cursor_get`] call
+            let data = unsafe { data.assume_init() };
+            // SAFETY: values returned from database are valid until a subsequent update
+            // operation, or the end of the transaction
+            let key = unsafe { slice::from_raw_parts(key.mv_data.cast(), key.mv_size) };
+            // SAFETY: values returned from database are valid until a subsequent update
+            // operation, or the end of the transaction
+            let data = unsafe { slice::from_raw_parts(data.mv_data.cast(), data.mv_size) };
+            Ok(Some((
+                DataView::new(self.txn, key),
+                DataView::new(self.txn, data),
+            )))
+        }
+
+        /// Positions the cursor at the first key/value pair in the database.
+        ///
+        /// # Errors
+        ///
+        /// * Returns an [`Error`] if any call to LMDB API fails.
+        #[inline]
+        pub fn first(&mut self) -> Result<Option<(DataView<'a>, DataView<'a>)>> {
+            self.get(None, sys::MDB_cursor_op_MDB_FIRST)
+        }
+
+        /// Positions the cursor at the last key/value pair in the database.
+        ///
+        /// # Errors
+        ///
+        /// * Returns an [`Error`] if any call to LMDB API fails.
+        #[inline]
+        pub fn last(&mut self) -> Result<Option<(DataView<'a>, DataView<'a>)>> {
+            self.get(None, sys::MDB_cursor_op_MDB_LAST)
+        }
+
+        /// Advances the cursor to the next key/value pair in the database.
+        ///
+        /// # Errors
+        ///
+        /// * Returns an [`Error`] if any call to LMDB API fails.
+        #[inline]
+        pub fn next(&mut self) -> Result<Option<(DataView<'a>, DataView<'a>)>> {
+            self.get(None, sys::MDB_cursor_op_MDB_NEXT)
+        }
+
+        /// Moves the cursor to the previous key/value pair in the database.
+        ///
+        /// # Errors
+        ///
+        /// * Returns an [`Error`] if any call to LMDB API fails.
+        #[inline]
+        pub fn prev(&mut self) -> Result<Option<(DataView<'a>, DataView<'a>)>> {
+            self.get(None, sys::MDB_cursor_op_MDB_PREV)
+        }
+
+        /// Positions the cursor at `key`, returning `None` if it doesn't exist.
+        ///
+        /// # Errors
+        ///
+        /// * Returns an [`Error`] if any call to LMDB API fails.
+        #[inline]
+        pub fn set(&mut self, key: &[u8]) -> Result<Option<(DataView<'a>, DataView<'a>)>> {
+            self.db.check_key_len(key)?;
+            self.get(Some(key), sys::MDB_cursor_op_MDB_SET_KEY)
+        }
+
+        /// Positions the cursor at the first key greater than or equal to
+        /// `key`.
+        ///
+        /// # Errors
+        ///
+        /// * Returns an [`Error`] if any call to LMDB API fails.
+        #[inline]
+        pub fn set_range(&mut self, key: &[u8]) -> Result<Option<(DataView<'a>, DataView<'a>)>> {
+            self.db.check_key_len(key)?;
+            self.get(Some(key), sys::MDB_cursor_op_MDB_SET_RANGE)
+        }
+
+        /// Returns an [`Iterator`] that positions this cursor at the first key
+        /// greater than or equal to `key` and then walks forward to the end of
+        /// the database.
+        #[inline]
+        #[must_use]
+        pub fn iter_from(self, key: &[u8]) -> Iter<'a> {
+            Iter {
+                cursor: self,
+                next_op: IterOp::SetRange(key.to_vec()),
+                done: false,
+            }
+        }
+    }
+
+    impl<'a> Drop for Cursor<'a> {
+        #[inline]
+        fn drop(&mut self) {
+            // SAFETY: this [`Cursor`] will not be used after this call so it's safe to
+            // close it
+            unsafe { sys::mdb_cursor_close(self.cursor) }
+        }
+    }
+
+    enum IterOp {
+        SetRange(Vec<u8>),
+        Next,
+    }
+
+    /// An [`Iterator`] over the key/value pairs of a [`Cursor`], advancing via
+    /// `MDB_NEXT` and stopping once the database is exhausted.
+    pub struct Iter<'a> {
+        cursor: Cursor<'a>,
+        next_op: IterOp,
+        done: bool,
+    }
+
+    impl<'a> Iterator for Iter<'a> {
+        type Item = Result<(DataView<'a>, DataView<'a>)>;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.done {
+                return None;
+            }
+            let item = match &self.next_op {
+                IterOp::SetRange(key) => self.cursor.set_range(key),
+                IterOp::Next => self.cursor.next(),
+            };
+            match item {
+                Ok(Some(pair)) => {
+                    self.next_op = IterOp::Next;
+                    Some(Ok(pair))
+                }
+                Ok(None) => {
+                    self.done = true;
+                    None
+                }
+                Err(e) => {
+                    self.done = true;
+                    Some(Err(e))
+                }
+            }
+        }
+    }
 }